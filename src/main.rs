@@ -2,7 +2,7 @@ use std::{
     cell::{RefCell, RefMut},
     collections::{HashMap, HashSet},
     io,
-    path::PathBuf,
+    path::{Path, PathBuf},
 };
 
 use clap::{Parser, Subcommand};
@@ -13,7 +13,8 @@ use mdbook::BookItem;
 use once_cell::sync::Lazy;
 use regex::Regex;
 use semver::{Version, VersionReq};
-use serde::Deserialize;
+use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Parser)]
 struct App {
@@ -40,6 +41,19 @@ pub struct Config {
     pub record_heading: String,
     /// Heading to use for the second column of the trace table.
     pub trace_heading: String,
+    /// Write a machine-readable trace database to this path, relative to the
+    /// book root. The file is not written into the renderer's build directory.
+    ///
+    /// The file extension selects the format: `trace.json` emits JSON, while
+    /// `trace.csv` emits one row per (target, record, trace, chapter).
+    pub export: Option<String>,
+    /// Fail the build when a target declares expected records that are left
+    /// uncovered (or picks up records it never declared). When `false` the
+    /// same gaps are reported as a warning on stderr instead.
+    pub strict: bool,
+    /// Reject traces to records that were never registered with a
+    /// `{{#tracedef}}` directive.
+    pub require_definitions: bool,
     /// Table of trace targets.
     pub targets: HashMap<String, TargetConfig>,
 }
@@ -53,14 +67,61 @@ impl Default for Config {
             parent_numbering: ParentNumbering::Zero,
             record_heading: "Record".to_string(),
             trace_heading: "Traces".to_string(),
+            export: None,
+            strict: false,
+            require_definitions: false,
             targets: HashMap::default(),
         }
     }
 }
 
 #[derive(Debug, Clone, serde::Deserialize)]
+#[serde(default)]
 pub struct TargetConfig {
     pub name: String,
+    /// Expected set of record IDs used for coverage validation.
+    pub records: Option<Records>,
+    /// Key records by their originating chapter as well as their id, so that
+    /// an identical name used in two chapters stays distinct in the matrix.
+    pub namespaced: bool,
+}
+
+impl Default for TargetConfig {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            records: None,
+            namespaced: false,
+        }
+    }
+}
+
+/// The declared record IDs for a target, used to validate coverage.
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(untagged)]
+pub enum Records {
+    /// An inline list of expected record IDs.
+    List(Vec<String>),
+    /// A path, relative to the book root, to a file listing one record ID per line.
+    Path(String),
+}
+
+impl Records {
+    /// Resolve the declared records into a set of record IDs.
+    fn resolve(&self, root: &Path) -> Result<HashSet<String>, Error> {
+        match self {
+            Records::List(list) => Ok(list.iter().cloned().collect()),
+            Records::Path(path) => {
+                let contents = std::fs::read_to_string(root.join(path))?;
+                Ok(contents
+                    .lines()
+                    .map(str::trim)
+                    .filter(|line| !line.is_empty())
+                    .map(String::from)
+                    .collect())
+            }
+        }
+    }
 }
 
 /// ParentNumbering defines the trace numbering strategy for a page with subchapters.
@@ -122,6 +183,22 @@ fn handle_preprocessing() -> Result<(), Error> {
     Ok(())
 }
 
+/// Join `fields` into a single CSV record, quoting any field that contains a
+/// comma, quote, or newline.
+fn csv_row(fields: &[&str]) -> String {
+    fields
+        .iter()
+        .map(|field| {
+            if field.contains([',', '"', '\n']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
 fn handle_supports(renderer: impl AsRef<str>) {
     let pre = Traceable::default();
     if pre.supports_renderer(renderer.as_ref()) {
@@ -144,7 +221,7 @@ impl Traceable {
             .iter()
             .map(|(k, v)| {
                 let id = k.clone();
-                let target = Target::new(&v.name);
+                let target = Target::new(&v.name, v.namespaced);
                 (id, target)
             })
             .collect::<HashMap<_, _>>();
@@ -179,6 +256,23 @@ impl Traceable {
         }
     }
 
+    fn generate_definitions(&self, chapter: &mut Chapter) -> Result<(), Error> {
+        let mut res = Ok(());
+        chapter.content = TRACEDEF_RE
+            .replace_all(&chapter.content, |caps: &regex::Captures| {
+                let target = caps.name("target").unwrap().as_str();
+                let record = caps.name("record").unwrap().as_str();
+                let description = caps.name("description").unwrap().as_str();
+                match self.target(target) {
+                    Ok(mut t) => t.define(record, description, chapter.path.as_deref()),
+                    Err(e) => res = Err(e),
+                }
+                String::new()
+            })
+            .to_string();
+        res
+    }
+
     fn generate_traces(&self, chapter: &mut Chapter) -> Result<(), Error> {
         let mut footnotes = vec![];
         let mut count = 0;
@@ -198,6 +292,17 @@ impl Traceable {
                     }
                 };
 
+                if self.config.require_definitions
+                    && !target.is_defined(record, chapter.path.as_deref())
+                {
+                    res = Err(anyhow::anyhow!(
+                        "trace to undefined record '{}' in target '{}'",
+                        record,
+                        target.name
+                    ));
+                    return String::new();
+                }
+
                 let mut number = chapter.number.clone().unwrap_or_default().0;
                 match self.config.parent_numbering {
                     ParentNumbering::Zero => {
@@ -239,6 +344,163 @@ impl Traceable {
         res
     }
 
+    /// Compare each target's declared records against the records actually
+    /// traced. A declared record with no traces is *uncovered*; a traced
+    /// record that was never declared is *unknown*. When [`Config::strict`] is
+    /// set any gap returns an `Err`, otherwise it is reported on stderr.
+    fn validate_coverage(&self, ctx: &PreprocessorContext) -> Result<(), Error> {
+        let targets = self.targets.borrow();
+        let mut summary = String::new();
+
+        for (id, cfg) in &self.config.targets {
+            let Some(declared) = &cfg.records else {
+                continue;
+            };
+            let declared = declared.resolve(&ctx.root)?;
+            let target = &targets[id];
+
+            // Coverage is declared against bare record names, so compare on
+            // names rather than the (possibly namespaced) map keys.
+            let covered = target
+                .records
+                .values()
+                .filter(|record| !record.traces.is_empty())
+                .map(|record| record.name.clone())
+                .collect::<HashSet<_>>();
+            let mut uncovered = declared
+                .iter()
+                .filter(|name| !covered.contains(*name))
+                .cloned()
+                .collect::<Vec<_>>();
+            // "unknown" means a *traced* record missing from the declared set;
+            // records that exist only as link placeholders or bare `{{#tracedef}}`
+            // entries carry no traces and must not fail the build.
+            let mut unknown = target
+                .records
+                .values()
+                .filter(|record| !record.traces.is_empty())
+                .map(|record| record.name.clone())
+                .filter(|name| !declared.contains(name))
+                .collect::<Vec<_>>();
+            uncovered.sort();
+            unknown.sort();
+            unknown.dedup();
+
+            if !uncovered.is_empty() {
+                summary.push_str(&format!(
+                    "{}: uncovered records: {}\n",
+                    cfg.name,
+                    uncovered.join(", ")
+                ));
+            }
+            if !unknown.is_empty() {
+                summary.push_str(&format!(
+                    "{}: unknown records: {}\n",
+                    cfg.name,
+                    unknown.join(", ")
+                ));
+            }
+        }
+
+        if summary.is_empty() {
+            return Ok(());
+        }
+
+        let summary = summary.trim_end();
+        if self.config.strict {
+            Err(anyhow::anyhow!("trace coverage gaps:\n{}", summary))
+        } else {
+            eprintln!("Warning: trace coverage gaps:\n{}", summary);
+            Ok(())
+        }
+    }
+
+    /// Serialize the populated trace database, relative to the book root.
+    ///
+    /// The file is written under [`PreprocessorContext::root`] rather than the
+    /// renderer's build directory, since the HTML renderer clears its
+    /// destination before writing and would otherwise wipe the export. The
+    /// path is chosen by [`Config::export`]; its extension selects the format.
+    /// A `.csv` file contains one row per (target, record, trace, chapter
+    /// path); anything else is written as pretty-printed JSON.
+    fn export(&self, ctx: &PreprocessorContext) -> Result<(), Error> {
+        let Some(export) = &self.config.export else {
+            return Ok(());
+        };
+
+        let path = ctx.root.join(export);
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+
+        let targets = self.targets.borrow();
+        if path.extension().and_then(|e| e.to_str()) == Some("csv") {
+            let mut rows = vec!["target,record,number,path".to_string()];
+            let mut ids = targets.keys().cloned().collect::<Vec<_>>();
+            ids.sort();
+            for id in ids {
+                let target = &targets[&id];
+                let mut records = target.records.values().collect::<Vec<_>>();
+                records.sort_by(|a, b| a.name.cmp(&b.name));
+                for record in records {
+                    for trace in &record.traces {
+                        let path = trace
+                            .path
+                            .as_ref()
+                            .map(|p| p.display().to_string())
+                            .unwrap_or_default();
+                        rows.push(csv_row(&[
+                            &target.name,
+                            &record.name,
+                            &trace.number(".", true),
+                            &path,
+                        ]));
+                    }
+                }
+            }
+            std::fs::write(path, rows.join("\n"))?;
+        } else {
+            let file = std::fs::File::create(path)?;
+            serde_json::to_writer_pretty(file, &*targets)?;
+        }
+
+        Ok(())
+    }
+
+    fn generate_links(&self, chapter: &mut Chapter) -> Result<(), Error> {
+        let mut res = Ok(());
+        chapter.content = TRACELINK_RE
+            .replace_all(&chapter.content, |caps: &regex::Captures| {
+                let target = caps.name("target").unwrap().as_str();
+                let parent_target = caps.name("parent_target").unwrap().as_str();
+                if target != parent_target {
+                    res = Err(anyhow::anyhow!(
+                        "tracelink across targets is not supported: '{}' -> '{}'",
+                        target,
+                        parent_target
+                    ));
+                    return String::new();
+                }
+                let child = caps.name("child").unwrap().as_str();
+                let parent = caps.name("parent").unwrap().as_str();
+                match self.target(target) {
+                    Ok(mut t) => t.add_link(child, parent, chapter.path.as_deref()),
+                    Err(e) => res = Err(e),
+                }
+                String::new()
+            })
+            .to_string();
+        res
+    }
+
+    /// Run a cycle check over the derived-requirement graph of every target.
+    fn check_cycles(&self) -> Result<(), Error> {
+        for target in self.targets.borrow().values() {
+            target.check_cycles()?;
+        }
+        Ok(())
+    }
+
     fn generate_tables(&self, chapter: &mut Chapter) -> Result<(), Error> {
         let mut res = Ok(());
         chapter.content = MATRIX_RE
@@ -262,19 +524,33 @@ impl Preprocessor for Traceable {
         "trace-preprocessor"
     }
 
-    fn run(&self, _ctx: &PreprocessorContext, mut book: Book) -> Result<Book, Error> {
+    fn run(&self, ctx: &PreprocessorContext, mut book: Book) -> Result<Book, Error> {
         let mut res = Ok(());
 
+        // Collect every record definition first, over the whole book, so that a
+        // trace validated in one chapter can reference a `{{#tracedef}}` that
+        // lives in a later chapter (e.g. a canonical requirements chapter).
         book.for_each_mut(|item| {
             if let BookItem::Chapter(chapter) = item {
                 if self.config.chapter_numbers {
                     self.number_headings(chapter);
                 }
+                if let Err(e) = self.generate_definitions(chapter) {
+                    res = Err(e);
+                }
+            }
+        });
+        book.for_each_mut(|item| {
+            if let BookItem::Chapter(chapter) = item {
                 if let Err(e) = self.generate_traces(chapter) {
                     res = Err(e);
                 }
+                if let Err(e) = self.generate_links(chapter) {
+                    res = Err(e);
+                }
             }
         });
+        res = res.and_then(|()| self.check_cycles());
         book.for_each_mut(|item| {
             if let BookItem::Chapter(chapter) = item {
                 if let Err(e) = self.generate_tables(chapter) {
@@ -283,6 +559,10 @@ impl Preprocessor for Traceable {
             }
         });
         res?;
+
+        self.validate_coverage(ctx)?;
+        self.export(ctx)?;
+
         Ok(book)
     }
 
@@ -291,53 +571,280 @@ impl Preprocessor for Traceable {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 struct Target {
     pub name: String,
     pub records: HashMap<String, Record>,
+    /// When set, records are keyed by (chapter, name) rather than name alone.
+    #[serde(skip)]
+    pub namespaced: bool,
+}
+
+/// Which optional columns a rendered matrix should include.
+#[derive(Debug, Clone, Copy)]
+struct Columns {
+    described: bool,
+    derived: bool,
+}
+
+/// DFS node state used by [`Target::check_cycles`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Color {
+    White,
+    Gray,
+    Black,
 }
 
 impl Target {
-    pub fn new(name: impl AsRef<str>) -> Self {
+    pub fn new(name: impl AsRef<str>, namespaced: bool) -> Self {
         Self {
             name: name.as_ref().to_string(),
             records: HashMap::default(),
+            namespaced,
         }
     }
 
     pub fn add_trace(&mut self, record: impl AsRef<str>, trace: Trace) {
-        let record = record.as_ref().to_string();
+        let (namespace, name) = self.resolve_record(record.as_ref(), trace.path.as_deref());
+        let key = Self::key(namespace.as_deref(), &name);
         self.records
-            .entry(record.clone())
-            .or_insert_with(|| Record::new(record))
+            .entry(key)
+            .or_insert_with(|| Record::new(name, namespace))
             .add_trace(trace);
     }
 
+    /// Register a record together with its human-readable description, ahead of
+    /// any trace that references it.
+    pub fn define(&mut self, record: impl AsRef<str>, description: &str, path: Option<&Path>) {
+        let (namespace, name) = self.resolve_record(record.as_ref(), path);
+        let key = Self::key(namespace.as_deref(), &name);
+        self.records
+            .entry(key)
+            .or_insert_with(|| Record::new(&name, namespace))
+            .description = Some(description.to_string());
+    }
+
+    /// Whether the record a trace points at has been registered with a
+    /// `{{#tracedef}}` directive.
+    pub fn is_defined(&self, record: impl AsRef<str>, path: Option<&Path>) -> bool {
+        let (namespace, name) = self.resolve_record(record.as_ref(), path);
+        let key = Self::key(namespace.as_deref(), &name);
+        self.records
+            .get(&key)
+            .map(|record| record.description.is_some())
+            .unwrap_or(false)
+    }
+
+    /// Split a directive's record string into an optional namespace and a bare
+    /// record name. A leading `::` or `global:` forces the record into the
+    /// shared global namespace even when the target is namespaced; otherwise a
+    /// namespaced target derives the namespace from the directive's chapter.
+    fn resolve_record(&self, record: &str, path: Option<&Path>) -> (Option<String>, String) {
+        if let Some(name) = record.strip_prefix("::").or_else(|| record.strip_prefix("global:")) {
+            return (None, name.trim().to_string());
+        }
+        if self.namespaced {
+            let namespace = path.map(|p| p.display().to_string()).unwrap_or_default();
+            (Some(namespace), record.to_string())
+        } else {
+            (None, record.to_string())
+        }
+    }
+
+    /// Build the `records` map key for a record from its namespace and name.
+    fn key(namespace: Option<&str>, name: &str) -> String {
+        match namespace {
+            Some(namespace) => format!("{}::{}", namespace, name),
+            None => name.to_string(),
+        }
+    }
+
+    /// Record that `child` is derived from `parent`, creating either record if
+    /// it has not been seen yet. Both records are resolved and keyed the same
+    /// way as traces (honouring the target's namespacing and the directive's
+    /// `::`/`global:` escapes) so that the edge lands on the real rows rather
+    /// than on phantom global-namespaced duplicates.
+    pub fn add_link(
+        &mut self,
+        child: impl AsRef<str>,
+        parent: impl AsRef<str>,
+        path: Option<&Path>,
+    ) {
+        let (parent_ns, parent_name) = self.resolve_record(parent.as_ref(), path);
+        let parent_key = Self::key(parent_ns.as_deref(), &parent_name);
+        self.records
+            .entry(parent_key.clone())
+            .or_insert_with(|| Record::new(&parent_name, parent_ns));
+
+        let (child_ns, child_name) = self.resolve_record(child.as_ref(), path);
+        let child_key = Self::key(child_ns.as_deref(), &child_name);
+        self.records
+            .entry(child_key)
+            .or_insert_with(|| Record::new(child_name, child_ns))
+            .parents
+            .insert(parent_key);
+    }
+
+    /// Detect a cycle in the derived-requirement graph using a depth-first
+    /// three-colour walk; a back-edge to a record still on the stack is a
+    /// cycle.
+    pub fn check_cycles(&self) -> Result<(), Error> {
+        let mut color: HashMap<&str, Color> =
+            self.records.keys().map(|k| (k.as_str(), Color::White)).collect();
+        for node in self.records.keys() {
+            if color[node.as_str()] == Color::White {
+                Self::visit(node, &self.records, &mut color)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn visit<'a>(
+        node: &'a str,
+        records: &'a HashMap<String, Record>,
+        color: &mut HashMap<&'a str, Color>,
+    ) -> Result<(), Error> {
+        color.insert(node, Color::Gray);
+        if let Some(record) = records.get(node) {
+            for parent in &record.parents {
+                match color.get(parent.as_str()).copied().unwrap_or(Color::White) {
+                    Color::Gray => {
+                        return Err(anyhow::anyhow!(
+                            "cycle detected in derived requirements: '{}' -> '{}'",
+                            node,
+                            parent
+                        ))
+                    }
+                    Color::White => Self::visit(parent, records, color)?,
+                    Color::Black => {}
+                }
+            }
+        }
+        color.insert(node, Color::Black);
+        Ok(())
+    }
+
     pub fn matrix(
         &self,
         record_heading: impl AsRef<str>,
         trace_heading: impl AsRef<str>,
     ) -> String {
-        let mut rows = vec![
-            format!(
-                "| {} | {} |",
-                record_heading.as_ref(),
-                trace_heading.as_ref()
-            ),
-            "|--------|--------|".to_string(),
-        ];
+        let derived = self.records.values().any(|r| !r.parents.is_empty());
+        let described = self.records.values().any(|r| r.description.is_some());
+        let namespaced = self.records.values().any(|r| r.namespace.is_some());
+        let cols = Columns { described, derived };
+
         let mut records = self.records.values().cloned().collect::<Vec<_>>();
-        records.sort_by(|a, b| a.name.cmp(&b.name));
+        records.sort_by(|a, b| (&a.namespace, &a.name).cmp(&(&b.namespace, &b.name)));
+
+        if !namespaced {
+            return self.table(&records, &record_heading, &trace_heading, cols);
+        }
+
+        // Group rows under a heading for their originating chapter, keeping
+        // records opted back into the global namespace in a single section.
+        let mut sections = vec![];
+        let mut group: Vec<Record> = vec![];
+        let mut current: Option<&Option<String>> = None;
+        for record in &records {
+            if current != Some(&record.namespace) {
+                if !group.is_empty() {
+                    sections.push(self.section(&group, &record_heading, &trace_heading, cols));
+                    group.clear();
+                }
+                current = Some(&record.namespace);
+            }
+            group.push(record.clone());
+        }
+        if !group.is_empty() {
+            sections.push(self.section(&group, &record_heading, &trace_heading, cols));
+        }
+        sections.join("\n\n")
+    }
+
+    /// Render a single section (chapter heading plus table) for a group of
+    /// records sharing one namespace.
+    fn section(
+        &self,
+        records: &[Record],
+        record_heading: impl AsRef<str>,
+        trace_heading: impl AsRef<str>,
+        cols: Columns,
+    ) -> String {
+        let heading = match records.first().and_then(|r| r.namespace.as_ref()) {
+            Some(namespace) => namespace.clone(),
+            None => "Global".to_string(),
+        };
+        let table = self.table(records, record_heading, trace_heading, cols);
+        format!("**{}**\n\n{}", heading, table)
+    }
+
+    /// Render a markdown table for the given records. Optional columns are
+    /// added per [`Columns`]: a description when any record is defined and a
+    /// "Derived From" column when any record has parents.
+    fn table(
+        &self,
+        records: &[Record],
+        record_heading: impl AsRef<str>,
+        trace_heading: impl AsRef<str>,
+        cols: Columns,
+    ) -> String {
+        let mut header = format!("| {} |", record_heading.as_ref());
+        let mut divider = "|--------|".to_string();
+        if cols.described {
+            header.push_str(" Description |");
+            divider.push_str("--------|");
+        }
+        header.push_str(&format!(" {} |", trace_heading.as_ref()));
+        divider.push_str("--------|");
+        if cols.derived {
+            header.push_str(" Derived From |");
+            divider.push_str("--------|");
+        }
+
+        let mut rows = vec![header, divider];
         for record in records {
-            rows.push(format!(
-                "| {} | {} |",
-                record.name,
-                record.references().join(", ")
-            ));
+            let key = Self::key(record.namespace.as_deref(), &record.name);
+            let mut row = format!(
+                "| <a name=\"{}\"></a>{} |",
+                Self::record_anchor(&key),
+                record.name
+            );
+            if cols.described {
+                row.push_str(&format!(
+                    " {} |",
+                    record.description.as_deref().unwrap_or_default()
+                ));
+            }
+            row.push_str(&format!(" {} |", record.references().join(", ")));
+            if cols.derived {
+                let mut parents = record
+                    .parents
+                    .iter()
+                    .map(|key| {
+                        // Link to the parent's row; fall back to its key if the
+                        // parent record somehow went unregistered.
+                        let name = self.records.get(key).map(|r| r.name.as_str()).unwrap_or(key);
+                        format!("[{}](#{})", name, Self::record_anchor(key))
+                    })
+                    .collect::<Vec<_>>();
+                parents.sort();
+                row.push_str(&format!(" {} |", parents.join(", ")));
+            }
+            rows.push(row);
         }
         rows.join("\n")
     }
 
+    /// Build a stable in-page anchor id for a record row from its map key.
+    fn record_anchor(key: &str) -> String {
+        let slug = key
+            .chars()
+            .map(|c| if c.is_alphanumeric() { c } else { '_' })
+            .collect::<String>();
+        format!("record_{}", slug)
+    }
+
     pub fn footnote(&self, trace: &Trace) -> Option<(String, String)> {
         for (_, record) in self.records.iter() {
             if record.traces.contains(trace) {
@@ -350,17 +857,26 @@ impl Target {
     }
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 struct Record {
     pub name: String,
+    /// Originating chapter when the record's target is namespaced.
+    pub namespace: Option<String>,
+    /// Human-readable description registered by a `{{#tracedef}}` directive.
+    pub description: Option<String>,
     pub traces: HashSet<Trace>,
+    /// Records this one is derived from (parent requirements).
+    pub parents: HashSet<String>,
 }
 
 impl Record {
-    pub fn new(name: impl AsRef<str>) -> Self {
+    pub fn new(name: impl AsRef<str>, namespace: Option<String>) -> Self {
         Self {
             name: name.as_ref().to_string(),
+            namespace,
+            description: None,
             traces: HashSet::new(),
+            parents: HashSet::new(),
         }
     }
 
@@ -439,6 +955,20 @@ impl Trace {
     }
 }
 
+impl Serialize for Trace {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Trace", 4)?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field("number", &self.number)?;
+        state.serialize_field("qualified", &self.number(".", true))?;
+        state.serialize_field("link", &self.link())?;
+        state.end()
+    }
+}
+
 /// Regex that captures a command in one of the following forms:
 ///   - `{{#trace <target>:<record>}}`
 ///   - `{{#tr <target>:<record>}}`
@@ -446,6 +976,22 @@ static TRACE_RE: Lazy<Regex> = Lazy::new(|| {
     Regex::new(r"(?s)\{\{#(?:trace|tr)\s+(?P<target>[a-zA-Z0-9_\-]+):\s*(?P<record>.*?)\s*\}\}")
         .unwrap()
 });
+/// Regex that captures a record definition of the form:
+///   - `{{#tracedef <target>:<record> <description>}}`
+static TRACEDEF_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?s)\{\{#tracedef\s+(?P<target>[a-zA-Z0-9_\-]+):\s*(?P<record>\S+)\s+(?P<description>.*?)\s*\}\}",
+    )
+    .unwrap()
+});
+/// Regex that captures a derived-requirement link of the form:
+///   - `{{#tracelink <target>:<child> -> <target>:<parent>}}`
+static TRACELINK_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?s)\{\{#tracelink\s+(?P<target>[a-zA-Z0-9_\-]+):\s*(?P<child>.*?)\s*->\s*(?P<parent_target>[a-zA-Z0-9_\-]+):\s*(?P<parent>.*?)\s*\}\}",
+    )
+    .unwrap()
+});
 /// Regex that captures a command in one of the following forms:
 ///   - `{{#tracematrix <target> }}`
 ///   - `{{#trace_matrix <target> }}`